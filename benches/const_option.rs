@@ -0,0 +1,75 @@
+//! Compares `ConstOption` against `std::option::Option` for the basic operations, to back up the
+//! claim that the compile-time presence costs nothing at runtime.
+
+use std::hint::black_box;
+
+use const_either::ConstOption;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+fn construct(c: &mut Criterion) {
+    let mut group = c.benchmark_group("construct");
+    group.bench_function("ConstOption<u64, true>", |b| {
+        b.iter(|| ConstOption::<u64, true>::new(black_box(42)))
+    });
+    group.bench_function("Option<u64> (Some)", |b| b.iter(|| Some(black_box(42u64))));
+    group.bench_function("ConstOption<u64, false>", |b| {
+        b.iter(|| black_box(ConstOption::<u64, false>::new()))
+    });
+    group.bench_function("Option<u64> (None)", |b| b.iter(|| black_box(None::<u64>)));
+    group.finish();
+}
+
+fn into_inner(c: &mut Criterion) {
+    let mut group = c.benchmark_group("into_inner");
+    group.bench_function("ConstOption<u64, true>", |b| {
+        b.iter(|| black_box(ConstOption::<u64, true>::new(black_box(42))).into_inner())
+    });
+    group.bench_function("Option<u64>", |b| {
+        b.iter(|| black_box(Some(black_box(42u64))).unwrap())
+    });
+    group.finish();
+}
+
+fn map(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map");
+    group.bench_function("ConstOption<u64, true>", |b| {
+        b.iter(|| {
+            black_box(ConstOption::<u64, true>::new(black_box(42))).and_then_present(|x| x + 1)
+        })
+    });
+    group.bench_function("Option<u64>", |b| {
+        b.iter(|| black_box(Some(black_box(42u64))).map(|x| x + 1))
+    });
+    group.finish();
+}
+
+fn drop_value(c: &mut Criterion) {
+    let mut group = c.benchmark_group("drop");
+    group.bench_function("ConstOption<String, true>", |b| {
+        b.iter_batched(
+            || ConstOption::<String, true>::new("hello".to_string()),
+            std::mem::drop,
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("Option<String>", |b| {
+        b.iter_batched(|| Some("hello".to_string()), std::mem::drop, BatchSize::SmallInput)
+    });
+    group.finish();
+}
+
+fn empty(c: &mut Criterion) {
+    // An empty option has nothing to initialize, so constructing one never allocates or copies,
+    // no matter how large `T` is. It still reserves space for `T`, as described in the crate docs.
+    let mut group = c.benchmark_group("empty");
+    group.bench_function("ConstOption<[u64; 512], false>", |b| {
+        b.iter(|| black_box(ConstOption::<[u64; 512], false>::new()))
+    });
+    group.bench_function("Option<[u64; 512]> (None)", |b| {
+        b.iter(|| black_box(None::<[u64; 512]>))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, construct, into_inner, map, drop_value, empty);
+criterion_main!(benches);
@@ -0,0 +1,131 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::ConstEither;
+
+/// A [`ConstEither`] that stores its active side on the heap, behind the `alloc` feature.
+///
+/// A `ConstEither<L, R, IS_RIGHT>` is as large as the largest of `L` and `R`, even when the
+/// smaller one is active. A `BoxedEither` is always a single pointer instead, which is better
+/// suited for large variants that are rarely accessed, at the cost of an allocation.
+///
+/// # Example
+///
+/// ```ignore
+/// struct Node<const IS_LEAF: bool> {
+///     data: BoxedEither<[Node<false>; 16], [u8; 4096], IS_LEAF>,
+/// }
+/// ```
+pub struct BoxedEither<L, R, const IS_RIGHT: bool>(ConstEither<Box<L>, Box<R>, IS_RIGHT>);
+
+impl<L, R> BoxedEither<L, R, false> {
+    pub fn new(left: L) -> Self {
+        BoxedEither(ConstEither::<_, _, false>::new(Box::new(left)))
+    }
+
+    pub fn into_inner(self) -> L {
+        *self.0.into_inner()
+    }
+
+    /// Swaps the sides of the either. The value stays in the same allocation.
+    pub fn flip(self) -> BoxedEither<R, L, true> {
+        BoxedEither(self.0.flip())
+    }
+}
+
+impl<L, R> BoxedEither<L, R, true> {
+    pub fn new(right: R) -> Self {
+        BoxedEither(ConstEither::<_, _, true>::new(Box::new(right)))
+    }
+
+    pub fn into_inner(self) -> R {
+        *self.0.into_inner()
+    }
+
+    /// Swaps the sides of the either. The value stays in the same allocation.
+    pub fn flip(self) -> BoxedEither<R, L, false> {
+        BoxedEither(self.0.flip())
+    }
+}
+
+impl<L, R> AsRef<L> for BoxedEither<L, R, false> {
+    fn as_ref(&self) -> &L {
+        &self.0
+    }
+}
+
+impl<L, R> AsRef<R> for BoxedEither<L, R, true> {
+    fn as_ref(&self) -> &R {
+        &self.0
+    }
+}
+
+impl<L, R> AsMut<L> for BoxedEither<L, R, false> {
+    fn as_mut(&mut self) -> &mut L {
+        &mut self.0
+    }
+}
+
+impl<L, R> AsMut<R> for BoxedEither<L, R, true> {
+    fn as_mut(&mut self) -> &mut R {
+        &mut self.0
+    }
+}
+
+impl<L, R> Deref for BoxedEither<L, R, false> {
+    type Target = L;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<L, R> Deref for BoxedEither<L, R, true> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<L, R> DerefMut for BoxedEither<L, R, false> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut()
+    }
+}
+
+impl<L, R> DerefMut for BoxedEither<L, R, true> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::Infallible, mem::size_of};
+
+    use super::*;
+
+    #[test]
+    fn nothing() {
+        let mut right = BoxedEither::<Infallible, usize, true>::new(1234);
+
+        assert_eq!(*right, 1234);
+        *right = 456;
+        assert_eq!(right.into_inner(), 456);
+    }
+
+    #[test]
+    fn flip() {
+        let left = BoxedEither::<String, [u8; 64], false>::new("hello".to_string());
+        let right: BoxedEither<[u8; 64], String, true> = left.flip();
+        assert_eq!(*right, "hello");
+        assert_eq!(right.flip().into_inner(), "hello");
+    }
+
+    #[test]
+    fn pointer_sized() {
+        assert_eq!(size_of::<BoxedEither<[u8; 4096], u8, false>>(), size_of::<usize>());
+        assert_eq!(size_of::<BoxedEither<String, [u64; 512], true>>(), size_of::<usize>());
+        assert!(size_of::<ConstEither<[u8; 4096], u8, false>>() >= 4096);
+    }
+}
@@ -35,10 +35,36 @@
 //! # Drawbacks
 //!
 //! Because of the current state of rust, the type `ConstEither<L, R>` **will have the size and
-//! alignment of the largest** from `L` and `R`.
+//! alignment of the largest** from `L` and `R`. With the `alloc` feature, `BoxedEither<L, R>` can
+//! be used instead, which stores the active side on the heap and is always pointer-sized.
 //!
+#![cfg_attr(feature = "nightly", feature(marker_trait_attr, try_trait_v2, try_trait_v2_residual))]
 
-use std::{mem::ManuallyDrop, ops::{Deref, DerefMut}};
+use std::{
+    borrow::{Borrow, Cow},
+    collections::BinaryHeap,
+    error::Error,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(feature = "alloc")]
+mod boxed;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "nightly")]
+mod try_impl;
+
+#[cfg(feature = "alloc")]
+pub use boxed::BoxedEither;
+
+#[cfg(feature = "serde")]
+pub use serde_impl::{AdjacentlyTagged, Untagged};
 
 /// An `Option` type that is known at compile-time to have or not some value. This is usefull for
 /// writing data structures that use const generics and would like to hold or not a value of some
@@ -53,6 +79,33 @@ use std::{mem::ManuallyDrop, ops::{Deref, DerefMut}};
 ///     dog_name: ConstOption<String, HAS_DOG>,
 /// }
 /// ```
+///
+/// # Thread safety
+///
+/// `ConstOption<T, IS_SOME>` is `Send` and `Sync` exactly when `T` is, regardless of `IS_SOME`.
+/// This means that even an empty option of a `!Send` type, such as `ConstOption<Rc<u8>, false>`,
+/// is `!Send`.
+///
+/// ```compile_fail
+/// # use std::rc::Rc;
+/// # use const_either::ConstOption;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<ConstOption<Rc<u8>, true>>();
+/// ```
+///
+/// ```compile_fail
+/// # use std::rc::Rc;
+/// # use const_either::ConstOption;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<ConstOption<Rc<u8>, false>>();
+/// ```
+///
+/// ```compile_fail
+/// # use std::cell::Cell;
+/// # use const_either::ConstOption;
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<ConstOption<Cell<u8>, true>>();
+/// ```
 pub struct ConstOption<T, const IS_SOME: bool>(ConstOptionInner<T, IS_SOME>);
 
 union ConstOptionInner<T, const IS_SOME: bool> {
@@ -61,18 +114,326 @@ union ConstOptionInner<T, const IS_SOME: bool> {
 }
 
 impl<T> ConstOption<T, false> {
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         ConstOption(ConstOptionInner { none: () })
     }
+
+    /// Fills the empty slot with `val`. This is the one way an empty option is promoted into a
+    /// present one, which the other filling methods go through.
+    pub const fn into_some(self, val: T) -> ConstOption<T, true> {
+        // There is nothing to drop, and destructors can't run in a `const fn`.
+        std::mem::forget(self);
+        ConstOption::<T, true>::new(val)
+    }
+
+    /// Fills the option with the `ConstDefault` of `T`. Unlike `Default`, this can be used in
+    /// `const` contexts.
+    pub const fn or_default(self) -> ConstOption<T, true>
+    where
+        T: ConstDefault,
+    {
+        self.into_some(T::DEFAULT)
+    }
+
+    /// Consumes the empty option. There is nothing inside, so this does nothing, but it gives
+    /// generic or macro-generated code the same "consume" verb as `into_inner` on present options.
+    pub fn into_nothing(self) {}
+
+    /// Yields nothing, since there is no value to repeat. See the `true` variant.
+    pub fn iter_repeat(self, _n: usize) -> impl Iterator<Item = T>
+    where
+        T: Clone,
+    {
+        std::iter::empty()
+    }
+
+    /// Views the empty option as an empty array, for interop with fixed-size array APIs.
+    pub fn as_ref_array(&self) -> &[T; 0] {
+        &[]
+    }
+
+    /// Turns the empty option into a right either holding the value computed by `right`. A
+    /// present option turns into a left either instead, so the tag is always `!IS_SOME`.
+    pub fn widen<R>(self, right: impl FnOnce() -> R) -> ConstEither<T, R, true> {
+        ConstEither::<T, R, true>::new(right())
+    }
+
+    /// Validates presence, routing the missing value to the right side as `err`. A present option
+    /// turns into a left either instead, so the tag is always `!IS_SOME`.
+    pub fn map_err_to_either<E>(self, err: E) -> ConstEither<T, E, true> {
+        ConstEither::<T, E, true>::new(err)
+    }
+
+    /// Fills the option with the value from `f` and runs `g` on it, returning `g`'s result
+    /// together with the now present option.
+    pub fn ensure_and<R>(
+        self,
+        f: impl FnOnce() -> T,
+        g: impl FnOnce(&mut T) -> R,
+    ) -> (R, ConstOption<T, true>) {
+        let mut some = self.into_some(f());
+        (g(&mut some), some)
+    }
+}
+
+impl<T> Default for ConstOption<T, false> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> ConstOption<T, true> {
-    pub fn new(val: T) -> Self {
+    pub const fn new(val: T) -> Self {
         ConstOption(ConstOptionInner { some: ManuallyDrop::new(val) })
     }
 
-    pub fn into_inner(mut self) -> T {
-        unsafe { ManuallyDrop::take(&mut self.0.some) }
+    pub fn into_inner(self) -> T {
+        let mut this = ManuallyDrop::new(self);
+        unsafe { ManuallyDrop::take(&mut this.0.some) }
+    }
+
+    /// Validates presence, putting the value on the left side and dropping `err`. An empty option
+    /// turns into a right either holding `err` instead, so the tag is always `!IS_SOME`.
+    pub fn map_err_to_either<E>(self, _err: E) -> ConstEither<T, E, false> {
+        ConstEither::<T, E, false>::new(self.into_inner())
+    }
+
+    /// Returns a reference to the inner value. Same as `as_ref`, for code that prefers an explicit
+    /// method call over `Deref`.
+    pub fn unwrap_ref(&self) -> &T {
+        self.as_ref()
+    }
+
+    /// Returns a mutable reference to the inner value. Same as `as_mut`, for code that prefers an
+    /// explicit method call over `DerefMut`.
+    pub fn unwrap_mut(&mut self) -> &mut T {
+        self.as_mut()
+    }
+
+    /// Lazily yields the inner value `n` times, cloning it as needed.
+    pub fn iter_repeat(self, n: usize) -> impl Iterator<Item = T>
+    where
+        T: Clone,
+    {
+        std::iter::repeat_n(self.into_inner(), n)
+    }
+
+    /// Views the inner value as a one-element array, for interop with fixed-size array APIs.
+    pub fn as_ref_array(&self) -> &[T; 1] {
+        std::array::from_ref(self.as_ref())
+    }
+
+    /// Turns the present option into a left either, without calling `right`. An empty option
+    /// turns into a right either instead, so the tag is always `!IS_SOME`.
+    pub fn widen<R>(self, _right: impl FnOnce() -> R) -> ConstEither<T, R, false> {
+        ConstEither::<T, R, false>::new(self.into_inner())
+    }
+
+    /// Runs `g` on the inner value, returning its result together with the option. `f` is never
+    /// called, since the value is already present.
+    pub fn ensure_and<R>(
+        mut self,
+        _f: impl FnOnce() -> T,
+        g: impl FnOnce(&mut T) -> R,
+    ) -> (R, ConstOption<T, true>) {
+        (g(&mut self), self)
+    }
+
+    /// Replaces the inner value with `val` if `pred` holds for the current one, returning the old
+    /// value. Otherwise, `val` is dropped and the option is returned unchanged.
+    pub fn replace_if(
+        self,
+        val: T,
+        pred: impl FnOnce(&T) -> bool,
+    ) -> (Option<T>, ConstOption<T, true>) {
+        if pred(&self) {
+            (Some(self.into_inner()), ConstOption::<T, true>::new(val))
+        } else {
+            (None, self)
+        }
+    }
+
+    /// Splits the option into its value and an empty shell of the same type, so the two can be
+    /// moved around separately. See [`ConstOption::from_parts`] for the inverse.
+    pub fn into_parts(self) -> (T, ConstOption<T, false>) {
+        (self.into_inner(), ConstOption::<T, false>::new())
+    }
+
+    /// Puts `val` back into an empty shell, undoing [`ConstOption::into_parts`].
+    pub fn from_parts(val: T, empty: ConstOption<T, false>) -> ConstOption<T, true> {
+        empty.into_some(val)
+    }
+}
+
+impl<T, const IS_SOME: bool> ConstOption<T, IS_SOME> {
+    /// Moves the value out, if there is any, without running `Drop` on `self`.
+    fn into_option(self) -> Option<T> {
+        let mut this = ManuallyDrop::new(self);
+        if IS_SOME {
+            Some(unsafe { ManuallyDrop::take(&mut this.0.some) })
+        } else {
+            None
+        }
+    }
+
+    fn as_option(&self) -> Option<&T> {
+        if IS_SOME {
+            Some(unsafe { &self.0.some })
+        } else {
+            None
+        }
+    }
+
+    fn as_option_mut(&mut self) -> Option<&mut T> {
+        if IS_SOME {
+            Some(unsafe { &mut self.0.some })
+        } else {
+            None
+        }
+    }
+
+    /// Wraps `val` without checking its presence.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be `Some` if and only if `IS_SOME` is `true`.
+    unsafe fn from_option_unchecked(val: Option<T>) -> Self {
+        debug_assert_eq!(val.is_some(), IS_SOME);
+        match val {
+            Some(val) => ConstOption(ConstOptionInner { some: ManuallyDrop::new(val) }),
+            None => ConstOption(ConstOptionInner { none: () }),
+        }
+    }
+
+    /// Applies `f` to the value, if there is one, keeping the compile-time presence.
+    ///
+    /// This is the same operation as a `map`, named to make it clear that presence is preserved:
+    /// a `true` option stays `true` and `f` is never called on a `false` one. A runtime
+    /// `Option::and_then`, on the other hand, lets the closure decide whether there is a value,
+    /// which can't be expressed here without going through `Option`.
+    pub fn and_then_present<U>(self, f: impl FnOnce(T) -> U) -> ConstOption<U, IS_SOME> {
+        unsafe { ConstOption::from_option_unchecked(self.into_option().map(f)) }
+    }
+
+    /// Returns the inner value, or recovers from its absence with `f`.
+    ///
+    /// This is the recommended way of extracting the value in code that is generic over
+    /// `IS_SOME`. When the option is known to be present, prefer `into_inner`, which doesn't need
+    /// a fallback at all.
+    pub fn into_inner_or<F: FnOnce() -> T>(self, f: F) -> T {
+        self.into_option().unwrap_or_else(f)
+    }
+
+    /// Returns the inner value, or `T::default()` if there is none. See
+    /// [`ConstOption::into_inner_or`].
+    pub fn into_inner_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.into_option().unwrap_or_default()
+    }
+
+    /// Returns the inner value, or `Err(())` if there is none, for code that doesn't know the flag
+    /// and wants to propagate absence with `?`. See [`ConstOption::into_inner_or`].
+    #[allow(clippy::result_unit_err)]
+    pub fn try_into_inner(self) -> Result<T, ()> {
+        self.into_option().ok_or(())
+    }
+
+    /// Checks for presence, turning the option into a result that is ok exactly when the option
+    /// is present, and holds `err` otherwise. This is the canonical validation step for pipelines
+    /// built on [`ConstResult`].
+    pub fn validate<E>(self, err: E) -> ConstResult<T, E, IS_SOME> {
+        self.validate_with(|| err)
+    }
+
+    /// Like [`ConstOption::validate`], but only computes the error when the option is empty.
+    pub fn validate_with<E>(self, f: impl FnOnce() -> E) -> ConstResult<T, E, IS_SOME> {
+        unsafe {
+            match self.into_option() {
+                Some(val) => ConstResult::new_ok_unchecked(val),
+                None => ConstResult::new_err_unchecked(f()),
+            }
+        }
+    }
+
+    /// Converts into an option with presence `WANT`, which only succeeds if it is the same as
+    /// `IS_SOME`. Otherwise, `self` is given back unchanged.
+    pub fn into_const<const WANT: bool>(self) -> Result<ConstOption<T, WANT>, Self> {
+        if WANT == IS_SOME {
+            Ok(unsafe { ConstOption::from_option_unchecked(self.into_option()) })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Mutates the inner value in place with `f`, if there is one. This is a no-op for an empty
+    /// option.
+    pub fn modify_if_present(&mut self, f: impl FnOnce(&mut T)) {
+        if let Some(val) = self.as_option_mut() {
+            f(val);
+        }
+    }
+
+    /// Computes a value from a reference to the inner value with `f`, or from `default` if there
+    /// is none, without consuming `self`. Handy inside `Display` impls.
+    pub fn map_or_else_ref<U>(&self, default: impl FnOnce() -> U, f: impl FnOnce(&T) -> U) -> U {
+        self.as_option().map_or_else(default, f)
+    }
+}
+
+impl<L, R, const IS_SOME: bool, const IS_RIGHT: bool>
+    ConstOption<ConstEither<L, R, IS_RIGHT>, IS_SOME>
+{
+    /// Pushes the presence of the option through the either. The resulting either has the same
+    /// tag, `IS_RIGHT`, and both of its sides are options with the same presence, `IS_SOME`. When
+    /// `self` is empty, the active side of the result holds an empty option.
+    pub fn transpose(
+        self,
+    ) -> ConstEither<ConstOption<L, IS_SOME>, ConstOption<R, IS_SOME>, IS_RIGHT> {
+        unsafe {
+            let (left, right) = match self.into_option() {
+                Some(either) => either.either(|l| (Some(l), None), |r| (None, Some(r))),
+                None => (None, None),
+            };
+            if IS_RIGHT {
+                ConstEither::new_right_unchecked(ConstOption::from_option_unchecked(right))
+            } else {
+                ConstEither::new_left_unchecked(ConstOption::from_option_unchecked(left))
+            }
+        }
+    }
+}
+
+impl<T> ConstOption<T, true> {
+    /// Returns a reference to the inner value. See the empty variant for the default fallback.
+    pub fn get_or_static_default(&self) -> &T {
+        self.as_ref()
+    }
+}
+
+impl<T: ConstDefault + 'static> ConstOption<T, false> {
+    /// Returns a reference to [`ConstDefault::default_ref`], a default instance of `T` that is
+    /// shared by the whole program, so the same reference is returned on every call.
+    pub fn get_or_static_default(&self) -> &T {
+        T::default_ref()
+    }
+}
+
+impl<T, const IS_SOME: bool> ConstOption<Vec<ConstOption<T, true>>, IS_SOME> {
+    /// Unwraps every element of the inner vec, or returns an empty vec if there is none.
+    pub fn flatten_into_vec(self) -> Vec<T> {
+        self.into_option()
+            .map(|vec| vec.into_iter().map(ConstOption::into_inner).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl<T, const IS_SOME: bool> ConstOption<Option<T>, IS_SOME> {
+    /// Collapses the compile-time presence and the runtime `Option` inside of it into a single
+    /// runtime `Option`.
+    pub fn flatten_std(self) -> Option<T> {
+        self.into_option().flatten()
     }
 }
 
@@ -112,6 +473,265 @@ impl<T> DerefMut for ConstOption<T, true> {
     }
 }
 
+impl<T: Deref> ConstOption<T, true> {
+    /// Dereferences the inner value, going through smart pointers such as `Box<dyn Trait>` so that
+    /// `ConstOption<Box<dyn Trait>, true>` can be used as a `&dyn Trait`. Such an option can be
+    /// created with [`ConstOption::new`], which coerces the box like any other function argument.
+    pub fn as_deref(&self) -> &T::Target {
+        self.as_ref().deref()
+    }
+}
+
+impl<T: DerefMut> ConstOption<T, true> {
+    /// Mutable version of [`ConstOption::as_deref`].
+    pub fn as_deref_mut(&mut self) -> &mut T::Target {
+        self.as_mut().deref_mut()
+    }
+}
+
+impl<I: Iterator, const IS_SOME: bool> ConstOption<I, IS_SOME> {
+    /// Turns the option into an iterator over the items of the inner iterator, yielding nothing if
+    /// there is none.
+    ///
+    /// `ConstOption` itself doesn't implement `Iterator` for an inner iterator, since its
+    /// `IntoIterator` impl already yields the inner value at most once: `option.into_iter()`
+    /// yields the iterator `I`, while `option.inner_iter()` yields its items. For a present
+    /// option, `Iterator::by_ref` and the other adapters can also be used on the inner iterator
+    /// directly through `DerefMut`.
+    pub fn inner_iter(self) -> InnerIter<I> {
+        InnerIter(self.into_option())
+    }
+}
+
+impl<T: Ord> ConstOption<T, true> {
+    /// Returns the larger of the two values, or `self` if `other` is empty. Like [`Ord::max`],
+    /// `other` is returned when both are equal.
+    pub fn max<const IS_B: bool>(self, other: ConstOption<T, IS_B>) -> ConstOption<T, true> {
+        match other.into_option() {
+            Some(other) => ConstOption::<T, true>::new(self.into_inner().max(other)),
+            None => self,
+        }
+    }
+
+    /// Returns the smaller of the two values, or `self` if `other` is empty. Like [`Ord::min`],
+    /// `self` is returned when both are equal.
+    pub fn min<const IS_B: bool>(self, other: ConstOption<T, IS_B>) -> ConstOption<T, true> {
+        match other.into_option() {
+            Some(other) => ConstOption::<T, true>::new(self.into_inner().min(other)),
+            None => self,
+        }
+    }
+}
+
+impl<T: Ord> ConstOption<T, false> {
+    /// Returns `other`, since there is nothing to compare it against.
+    pub fn max<const IS_B: bool>(self, other: ConstOption<T, IS_B>) -> ConstOption<T, IS_B> {
+        other
+    }
+
+    /// Returns `other`, since there is nothing to compare it against.
+    pub fn min<const IS_B: bool>(self, other: ConstOption<T, IS_B>) -> ConstOption<T, IS_B> {
+        other
+    }
+}
+
+impl<T: Ord> ConstOption<BinaryHeap<T>, true> {
+    /// Returns the greatest item of the inner heap, if any.
+    ///
+    /// Other collection methods, like `push` and `pop`, can be called on the option directly
+    /// through `DerefMut`, without going through `as_mut`.
+    pub fn peek_inner(&self) -> Option<&T> {
+        self.as_ref().peek()
+    }
+}
+
+impl<T: ?Sized + ToOwned> ConstOption<Cow<'_, T>, true> {
+    /// Acquires a mutable reference to the owned form of the inner `Cow`, cloning it if it is
+    /// borrowed.
+    pub fn to_mut(&mut self) -> &mut T::Owned {
+        self.as_mut().to_mut()
+    }
+
+    /// Turns the inner `Cow` into its owned form, cloning it if it is borrowed.
+    pub fn into_owned(self) -> ConstOption<T::Owned, true> {
+        ConstOption::<T::Owned, true>::new(self.into_inner().into_owned())
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for ConstOption<T, true> {
+    type Error = Vec<T>;
+
+    /// Succeeds only if `vec` has exactly one element, giving back the vec otherwise.
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        <[T; 1]>::try_from(vec).map(|[val]| Self::new(val))
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for ConstOption<T, false> {
+    type Error = Vec<T>;
+
+    /// Succeeds only if `vec` is empty, giving back the vec otherwise.
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        if vec.is_empty() {
+            Ok(Self::new())
+        } else {
+            Err(vec)
+        }
+    }
+}
+
+impl<T, const IS_SOME: bool> IntoIterator for ConstOption<T, IS_SOME> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.into_option().into_iter())
+    }
+}
+
+impl<T, const IS_SOME: bool> Extend<ConstOption<T, IS_SOME>> for Vec<T> {
+    fn extend<I: IntoIterator<Item = ConstOption<T, IS_SOME>>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().flatten())
+    }
+}
+
+/// An iterator over the value of a [`ConstOption`], yielding it if it is present.
+#[derive(Clone, Debug)]
+pub struct IntoIter<T>(std::option::IntoIter<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// An iterator over the items of the iterator inside a [`ConstOption`], created by
+/// [`ConstOption::inner_iter`].
+#[derive(Clone, Debug)]
+pub struct InnerIter<I>(Option<I>);
+
+impl<I: Iterator> Iterator for InnerIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.0.as_mut()?.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.as_ref().map_or((0, Some(0)), Iterator::size_hint)
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for InnerIter<I> {
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.0.as_mut()?.next_back()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for InnerIter<I> {}
+
+impl<I: FusedIterator> FusedIterator for InnerIter<I> {}
+
+/// Unwraps every option of an array of present options.
+pub fn unwrap_all<T, const N: usize>(arr: [ConstOption<T, true>; N]) -> [T; N] {
+    arr.map(ConstOption::into_inner)
+}
+
+/// Unwraps every option of an array, using clones of `default` if they are empty.
+pub fn unwrap_all_or<T: Clone, const IS_SOME: bool, const N: usize>(
+    arr: [ConstOption<T, IS_SOME>; N],
+    default: T,
+) -> [T; N] {
+    arr.map(|opt| opt.into_option().unwrap_or_else(|| default.clone()))
+}
+
+/// Fails const-evaluation when `IS_SOME` is `false`. Used in an inline `const` block, this lets
+/// generic code statically require that an option is present, with a compile error pointing at
+/// the offending instantiation.
+///
+/// # Example
+///
+/// ```
+/// use const_either::{assert_some, ConstOption};
+///
+/// fn double<const IS_SOME: bool>(opt: ConstOption<u32, IS_SOME>) -> u32 {
+///     const { assert_some::<IS_SOME>() };
+///     opt.map_or_else_ref(|| unreachable!(), |val| val * 2)
+/// }
+///
+/// assert_eq!(double(ConstOption::<u32, true>::new(21)), 42);
+/// ```
+///
+/// Instantiating it with an empty option is rejected at compile-time:
+///
+/// ```compile_fail
+/// # use const_either::{assert_some, ConstOption};
+/// # fn double<const IS_SOME: bool>(opt: ConstOption<u32, IS_SOME>) -> u32 {
+/// #     const { assert_some::<IS_SOME>() };
+/// #     0
+/// # }
+/// double(ConstOption::<u32, false>::new());
+/// ```
+///
+/// Since the assertion is only checked when the function is instantiated, errors show up on
+/// `cargo build`, but not necessarily on `cargo check`.
+pub const fn assert_some<const IS_SOME: bool>() {
+    assert!(IS_SOME, "expected a present `ConstOption`");
+}
+
+/// Collects the ok values of `iter` into a present vec option, stopping at the first error.
+///
+/// Whether an error shows up is only known at runtime, so the outcome is a plain `Result` rather
+/// than a `ConstResult`.
+pub fn try_collect_const<T, E, I>(iter: I) -> Result<ConstOption<Vec<T>, true>, E>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    iter.into_iter().collect::<Result<_, _>>().map(ConstOption::<Vec<T>, true>::new)
+}
+
+/// A default value that is available at compile-time, allowing it to be used from `const fn`s such
+/// as [`ConstOption::or_default`].
+pub trait ConstDefault: Sized {
+    const DEFAULT: Self;
+
+    /// Returns a reference to a `static` holding [`ConstDefault::DEFAULT`], used by
+    /// [`ConstOption::get_or_static_default`]. Generic `static`s aren't supported, so each
+    /// implementation has to declare its own.
+    fn default_ref() -> &'static Self;
+}
+
+macro_rules! impl_const_default {
+    ($($ty:ty),*) => {
+        $(impl ConstDefault for $ty {
+            const DEFAULT: Self = 0;
+
+            fn default_ref() -> &'static Self {
+                static DEFAULT: $ty = 0;
+                &DEFAULT
+            }
+        })*
+    };
+}
+
+impl_const_default!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 /// An `Either` type that is known to hold a left or right value at compile-time. This allows data
 /// structures choose an appropriate type based on some compile-time determined policy.
 ///
@@ -142,6 +762,25 @@ impl<T> DerefMut for ConstOption<T, true> {
 ///     hobbies: TheOneVec<TheOneString<INLINE>, INLINE, 16>,
 /// }
 /// ```
+///
+/// # Thread safety
+///
+/// `ConstEither<L, R, IS_RIGHT>` is `Send` and `Sync` exactly when both `L` and `R` are, even
+/// though only one of them is ever stored.
+///
+/// ```compile_fail
+/// # use std::rc::Rc;
+/// # use const_either::ConstEither;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<ConstEither<Rc<u8>, u8, false>>();
+/// ```
+///
+/// ```compile_fail
+/// # use std::rc::Rc;
+/// # use const_either::ConstEither;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<ConstEither<Rc<u8>, u8, true>>();
+/// ```
 pub struct ConstEither<L, R, const IS_RIGHT: bool>(ConstEitherInner<L, R, IS_RIGHT>);
 
 union ConstEitherInner<L, R, const IS_RIGHT: bool> {
@@ -155,14 +794,27 @@ impl<L, R> ConstEither<L, R, false> {
         ConstEither(ConstEitherInner { left: ManuallyDrop::new(left) })
     }
 
-    pub fn into_inner(mut self) -> L {
-        unsafe { ManuallyDrop::take(&mut self.0.left) }
+    pub fn into_inner(self) -> L {
+        let mut this = ManuallyDrop::new(self);
+        unsafe { ManuallyDrop::take(&mut this.0.left) }
     }
 
+    /// Swaps the sides of the either, keeping the value.
+    ///
+    /// The value is moved out of the union and into a new one, instead of reinterpreting the
+    /// union in place with a `transmute` or `ptr::read`. `ConstEitherInner<L, R>` and
+    /// `ConstEitherInner<R, L>` are not `repr(C)`, so nothing guarantees that their layouts
+    /// match, and this stays sound (and Miri-clean) for any `L` and `R`. The move is exactly one
+    /// read of the active field and one write, which the compiler is free to elide.
     pub fn flip(self) -> ConstEither<R, L, true> {
         let val = self.into_inner();
         ConstEither::<R, L, true>::new(val)
     }
+
+    /// Borrows the value as a flipped either, leaving `self` untouched.
+    pub fn flip_ref(&self) -> ConstEither<&R, &L, true> {
+        ConstEither::<&R, &L, true>::new(self.as_ref())
+    }
 }
 
 impl<L, R> ConstEither<L, R, true> {
@@ -170,59 +822,247 @@ impl<L, R> ConstEither<L, R, true> {
         ConstEither(ConstEitherInner { right: ManuallyDrop::new(right) })
     }
 
-    pub fn into_inner(mut self) -> R {
-        unsafe { ManuallyDrop::take(&mut self.0.right) }
+    pub fn into_inner(self) -> R {
+        let mut this = ManuallyDrop::new(self);
+        unsafe { ManuallyDrop::take(&mut this.0.right) }
     }
 
+    /// Swaps the sides of the either, keeping the value. See the `false` variant for why this is a
+    /// move rather than an in-place reinterpretation.
     pub fn flip(self) -> ConstEither<R, L, false> {
         let val = self.into_inner();
         ConstEither::<R, L, false>::new(val)
     }
-}
 
-impl<L, R> AsRef<L> for ConstEither<L, R, false> {
-    fn as_ref(&self) -> &L {
-        unsafe { &self.0.left }
+    /// Borrows the value as a flipped either, leaving `self` untouched.
+    pub fn flip_ref(&self) -> ConstEither<&R, &L, false> {
+        ConstEither::<&R, &L, false>::new(self.as_ref())
     }
 }
 
-impl<L, R> AsRef<R> for ConstEither<L, R, true> {
-    fn as_ref(&self) -> &R {
-        unsafe { &self.0.right }
+impl<L, R, const IS_RIGHT: bool> ConstEither<L, R, IS_RIGHT> {
+    /// # Safety
+    ///
+    /// `IS_RIGHT` must be `false`.
+    unsafe fn new_left_unchecked(left: L) -> Self {
+        debug_assert!(!IS_RIGHT);
+        ConstEither(ConstEitherInner { left: ManuallyDrop::new(left) })
     }
-}
 
-impl<L, R> AsMut<L> for ConstEither<L, R, false> {
-    fn as_mut(&mut self) -> &mut L {
-        unsafe { &mut self.0.left }
+    /// # Safety
+    ///
+    /// `IS_RIGHT` must be `true`.
+    unsafe fn new_right_unchecked(right: R) -> Self {
+        debug_assert!(IS_RIGHT);
+        ConstEither(ConstEitherInner { right: ManuallyDrop::new(right) })
     }
-}
 
-impl<L, R> AsMut<R> for ConstEither<L, R, true> {
-    fn as_mut(&mut self) -> &mut R {
-        unsafe { &mut self.0.right }
+    /// Moves the active side out and passes it to the matching closure, without running `Drop` on
+    /// `self`.
+    fn either<U>(self, left: impl FnOnce(L) -> U, right: impl FnOnce(R) -> U) -> U {
+        let mut this = ManuallyDrop::new(self);
+        unsafe {
+            if IS_RIGHT {
+                right(ManuallyDrop::take(&mut this.0.right))
+            } else {
+                left(ManuallyDrop::take(&mut this.0.left))
+            }
+        }
     }
-}
-
-impl<L, R> Deref for ConstEither<L, R, false> {
-    type Target = L;
 
-    fn deref(&self) -> &Self::Target {
-        self.as_ref()
+    /// Converts whichever side is active into a common type `T`.
+    pub fn into_common<T>(self) -> T
+    where
+        L: Into<T>,
+        R: Into<T>,
+    {
+        self.either(Into::into, Into::into)
     }
-}
 
-impl<L, R> Deref for ConstEither<L, R, true> {
-    type Target = R;
+    /// Same as [`ConstEither::into_common`]. One side is always active, so the default is never
+    /// actually needed; this exists for parity with [`ConstOption::into_inner_or_default`].
+    pub fn into_common_or_default<T: Default>(self) -> T
+    where
+        L: Into<T>,
+        R: Into<T>,
+    {
+        self.into_common()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        self.as_ref()
+    /// Converts into a `Result`, treating the left side as the ok value.
+    pub fn into_result_left(self) -> Result<L, R> {
+        self.either(Ok, Err)
     }
-}
 
-impl<L, R> DerefMut for ConstEither<L, R, false> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.as_mut()
+    /// Converts into a `Result`, treating the right side as the ok value.
+    pub fn into_result_right(self) -> Result<R, L> {
+        self.either(Err, Ok)
+    }
+
+    /// Mutably borrows the active side, keeping the same tag, so it can be modified in place.
+    pub fn as_mut_either(&mut self) -> ConstEither<&mut L, &mut R, IS_RIGHT> {
+        unsafe {
+            if IS_RIGHT {
+                ConstEither::new_right_unchecked(&mut *self.0.right)
+            } else {
+                ConstEither::new_left_unchecked(&mut *self.0.left)
+            }
+        }
+    }
+
+    /// The size of the active side, that is, `size_of::<L>()` or `size_of::<R>()`.
+    pub fn active_size(&self) -> usize {
+        if IS_RIGHT {
+            std::mem::size_of::<R>()
+        } else {
+            std::mem::size_of::<L>()
+        }
+    }
+
+    /// How many bytes of the either are unused by the active side. This is the overhead described
+    /// in the crate's "Drawbacks" section.
+    pub fn wasted_bytes(&self) -> usize {
+        std::mem::size_of::<Self>() - self.active_size()
+    }
+
+    /// Pairs the active sides of two eithers with the same tag.
+    pub fn zip<L2, R2>(
+        self,
+        other: ConstEither<L2, R2, IS_RIGHT>,
+    ) -> ConstEither<(L, L2), (R, R2), IS_RIGHT> {
+        unsafe {
+            match (self.into_result_right(), other.into_result_right()) {
+                (Err(left), Err(other)) => ConstEither::new_left_unchecked((left, other)),
+                (Ok(right), Ok(other)) => ConstEither::new_right_unchecked((right, other)),
+                // Both eithers have the same tag.
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    /// Returns the left value, or `default` if the either is right-tagged, in which case the right
+    /// value is dropped.
+    pub fn left_or(self, default: L) -> L {
+        self.either(|left| left, |_| default)
+    }
+
+    /// Returns the right value, or `default` if the either is left-tagged, in which case the left
+    /// value is dropped.
+    pub fn right_or(self, default: R) -> R {
+        self.either(|_| default, |right| right)
+    }
+
+    /// Views whichever side is active as a slice, e.g. `ConstEither<Vec<u8>, [u8; 4], IS_RIGHT>`
+    /// as `&[u8]`.
+    pub fn as_slice<T>(&self) -> &[T]
+    where
+        L: AsRef<[T]>,
+        R: AsRef<[T]>,
+    {
+        unsafe {
+            if IS_RIGHT {
+                <R as AsRef<[T]>>::as_ref(&self.0.right)
+            } else {
+                <L as AsRef<[T]>>::as_ref(&self.0.left)
+            }
+        }
+    }
+
+    /// Borrows whichever side is active as a common `Q`, e.g. `ConstEither<String, Box<str>,
+    /// IS_RIGHT>` as `&str`.
+    pub fn borrow_active<Q: ?Sized>(&self) -> &Q
+    where
+        L: Borrow<Q>,
+        R: Borrow<Q>,
+    {
+        unsafe {
+            if IS_RIGHT {
+                <R as Borrow<Q>>::borrow(&self.0.right)
+            } else {
+                <L as Borrow<Q>>::borrow(&self.0.left)
+            }
+        }
+    }
+}
+
+impl<T, const IS_RIGHT: bool> ConstEither<T, T, IS_RIGHT> {
+    /// Moves the value to the `NEW` side. Since both sides have the same type, the value is kept
+    /// as is, and only its position in the either changes.
+    pub fn reinterpret_same<const NEW: bool>(self) -> ConstEither<T, T, NEW> {
+        let val = self.either(|val| val, |val| val);
+        unsafe {
+            if NEW {
+                ConstEither::new_right_unchecked(val)
+            } else {
+                ConstEither::new_left_unchecked(val)
+            }
+        }
+    }
+}
+
+impl<T, U, const IS_RIGHT: bool> ConstEither<Vec<T>, Vec<U>, IS_RIGHT> {
+    /// Maps every element of whichever vec is active, passing along its index.
+    pub fn enumerate_map<A, B>(
+        self,
+        left: impl FnMut(usize, T) -> A,
+        right: impl FnMut(usize, U) -> B,
+    ) -> ConstEither<Vec<A>, Vec<B>, IS_RIGHT> {
+        fn enumerate_map<T, A>(vec: Vec<T>, mut f: impl FnMut(usize, T) -> A) -> Vec<A> {
+            vec.into_iter().enumerate().map(|(i, val)| f(i, val)).collect()
+        }
+
+        unsafe {
+            self.either(
+                |vec| ConstEither::new_left_unchecked(enumerate_map(vec, left)),
+                |vec| ConstEither::new_right_unchecked(enumerate_map(vec, right)),
+            )
+        }
+    }
+}
+
+impl<L, R> AsRef<L> for ConstEither<L, R, false> {
+    fn as_ref(&self) -> &L {
+        unsafe { &self.0.left }
+    }
+}
+
+impl<L, R> AsRef<R> for ConstEither<L, R, true> {
+    fn as_ref(&self) -> &R {
+        unsafe { &self.0.right }
+    }
+}
+
+impl<L, R> AsMut<L> for ConstEither<L, R, false> {
+    fn as_mut(&mut self) -> &mut L {
+        unsafe { &mut self.0.left }
+    }
+}
+
+impl<L, R> AsMut<R> for ConstEither<L, R, true> {
+    fn as_mut(&mut self) -> &mut R {
+        unsafe { &mut self.0.right }
+    }
+}
+
+impl<L, R> Deref for ConstEither<L, R, false> {
+    type Target = L;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<L, R> Deref for ConstEither<L, R, true> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<L, R> DerefMut for ConstEither<L, R, false> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut()
     }
 }
 
@@ -232,6 +1072,70 @@ impl<L, R> DerefMut for ConstEither<L, R, true> {
     }
 }
 
+impl<L: Default, R> Default for ConstEither<L, R, false> {
+    fn default() -> Self {
+        Self::new(L::default())
+    }
+}
+
+// An either can be decomposed into a pair of options where exactly one is present: a left either
+// has a present left option and an empty right option, and the other way around for a right one.
+
+impl<L, R> From<ConstEither<L, R, false>> for (ConstOption<L, true>, ConstOption<R, false>) {
+    fn from(either: ConstEither<L, R, false>) -> Self {
+        (ConstOption::<L, true>::new(either.into_inner()), ConstOption::<R, false>::new())
+    }
+}
+
+impl<L, R> From<ConstEither<L, R, true>> for (ConstOption<L, false>, ConstOption<R, true>) {
+    fn from(either: ConstEither<L, R, true>) -> Self {
+        (ConstOption::<L, false>::new(), ConstOption::<R, true>::new(either.into_inner()))
+    }
+}
+
+impl<L, R> From<(ConstOption<L, true>, ConstOption<R, false>)> for ConstEither<L, R, false> {
+    fn from((left, _): (ConstOption<L, true>, ConstOption<R, false>)) -> Self {
+        ConstEither::<L, R, false>::new(left.into_inner())
+    }
+}
+
+impl<L, R> From<(ConstOption<L, false>, ConstOption<R, true>)> for ConstEither<L, R, true> {
+    fn from((_, right): (ConstOption<L, false>, ConstOption<R, true>)) -> Self {
+        ConstEither::<L, R, true>::new(right.into_inner())
+    }
+}
+
+// The comparison and hashing impls only bound the active side, since the other one is never
+// stored. Values of the same type always have the same variant, so there is no tag to compare.
+
+impl<L: PartialEq, R> PartialEq for ConstEither<L, R, false> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<L, R: PartialEq> PartialEq for ConstEither<L, R, true> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<L: Eq, R> Eq for ConstEither<L, R, false> {}
+
+impl<L, R: Eq> Eq for ConstEither<L, R, true> {}
+
+impl<L: Hash, R> Hash for ConstEither<L, R, false> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<L, R: Hash> Hash for ConstEither<L, R, true> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
 impl<L, R, const IS_RIGHT: bool> Drop for ConstEither<L, R, IS_RIGHT> {
     fn drop(&mut self) {
         unsafe {
@@ -244,12 +1148,370 @@ impl<L, R, const IS_RIGHT: bool> Drop for ConstEither<L, R, IS_RIGHT> {
     }
 }
 
+/// Matches on a [`ConstEither`] with `match`-like syntax, consuming it. Only the active side is
+/// ever read, and since the tag is known at compile-time, the other arm is removed as dead code.
+///
+/// # Example
+///
+/// ```
+/// use const_either::{const_match, ConstEither};
+///
+/// fn describe<const IS_RIGHT: bool>(either: ConstEither<u8, String, IS_RIGHT>) -> String {
+///     const_match!(either,
+///         Left(n) => format!("number {}", n),
+///         Right(s) => format!("string {:?}", s),
+///     )
+/// }
+///
+/// assert_eq!(describe(ConstEither::<u8, String, false>::new(1)), "number 1");
+/// assert_eq!(describe(ConstEither::<u8, String, true>::new("a".into())), "string \"a\"");
+/// ```
+///
+/// The arms can be given in either order, and take any pattern:
+///
+/// ```
+/// # use const_either::{const_match, ConstEither};
+/// let right = ConstEither::<u8, (u8, u8), true>::new((1, 2));
+/// let sum = const_match!(right, Right((a, b)) => a + b, Left(n) => n);
+/// assert_eq!(sum, 3);
+/// ```
+#[macro_export]
+macro_rules! const_match {
+    ($either:expr, Left($left:pat) => $on_left:expr, Right($right:pat) => $on_right:expr $(,)?) => {
+        match $crate::ConstEither::into_result_right($either) {
+            ::core::result::Result::Err($left) => $on_left,
+            ::core::result::Result::Ok($right) => $on_right,
+        }
+    };
+    ($either:expr, Right($right:pat) => $on_right:expr, Left($left:pat) => $on_left:expr $(,)?) => {
+        $crate::const_match!($either, Left($left) => $on_left, Right($right) => $on_right)
+    };
+}
+
+/// A `Result` type that is known at compile-time to hold either an ok or an error value. Unlike
+/// `ConstEither`, the variants have the `Ok`/`Err` meaning of `std::result::Result`, with `IS_OK`
+/// telling which one is stored.
+///
+/// # Example
+///
+/// ```ignore
+/// fn parse<const STRICT: bool>(input: &str) -> ConstResult<Config, ParseError, STRICT> {
+///     /* ... */
+/// }
+/// ```
+pub struct ConstResult<T, E, const IS_OK: bool>(ConstResultInner<T, E, IS_OK>);
+
+union ConstResultInner<T, E, const IS_OK: bool> {
+    ok: ManuallyDrop<T>,
+    err: ManuallyDrop<E>,
+}
+
+impl<T, E> ConstResult<T, E, true> {
+    pub fn new(ok: T) -> Self {
+        ConstResult(ConstResultInner { ok: ManuallyDrop::new(ok) })
+    }
+
+    pub fn into_ok(self) -> T {
+        let mut this = ManuallyDrop::new(self);
+        unsafe { ManuallyDrop::take(&mut this.0.ok) }
+    }
+
+    /// Returns the ok value. Unlike `Result::unwrap`, this can never panic.
+    pub fn unwrap(self) -> T {
+        self.into_ok()
+    }
+
+    /// Returns the ok value. The message is never used, since this can't fail, but it eases
+    /// porting code from `Result::expect`.
+    pub fn expect(self, _msg: &str) -> T {
+        self.into_ok()
+    }
+}
+
+impl<T, E> ConstResult<T, E, false> {
+    pub fn new(err: E) -> Self {
+        ConstResult(ConstResultInner { err: ManuallyDrop::new(err) })
+    }
+
+    pub fn into_err(self) -> E {
+        let mut this = ManuallyDrop::new(self);
+        unsafe { ManuallyDrop::take(&mut this.0.err) }
+    }
+
+    /// Returns the err value. Unlike `Result::unwrap_err`, this can never panic.
+    pub fn unwrap_err(self) -> E {
+        self.into_err()
+    }
+
+    /// Returns the err value. The message is never used, since this can't fail, but it eases
+    /// porting code from `Result::expect_err`.
+    pub fn expect_err(self, _msg: &str) -> E {
+        self.into_err()
+    }
+}
+
+impl<T, E, const IS_OK: bool> ConstResult<T, E, IS_OK> {
+    /// # Safety
+    ///
+    /// `IS_OK` must be `true`.
+    unsafe fn new_ok_unchecked(ok: T) -> Self {
+        debug_assert!(IS_OK);
+        ConstResult(ConstResultInner { ok: ManuallyDrop::new(ok) })
+    }
+
+    /// # Safety
+    ///
+    /// `IS_OK` must be `false`.
+    unsafe fn new_err_unchecked(err: E) -> Self {
+        debug_assert!(!IS_OK);
+        ConstResult(ConstResultInner { err: ManuallyDrop::new(err) })
+    }
+
+    /// Converts into the structurally equivalent either, where the err value is on the left and
+    /// the ok value is on the right, so `IS_OK` becomes `IS_RIGHT`.
+    pub fn into_either(self) -> ConstEither<E, T, IS_OK> {
+        let mut this = ManuallyDrop::new(self);
+        unsafe {
+            if IS_OK {
+                ConstEither::new_right_unchecked(ManuallyDrop::take(&mut this.0.ok))
+            } else {
+                ConstEither::new_left_unchecked(ManuallyDrop::take(&mut this.0.err))
+            }
+        }
+    }
+
+    /// Converts from the structurally equivalent either, the inverse of
+    /// [`ConstResult::into_either`].
+    pub fn from_either(either: ConstEither<E, T, IS_OK>) -> Self {
+        unsafe {
+            either.either(|err| Self::new_err_unchecked(err), |ok| Self::new_ok_unchecked(ok))
+        }
+    }
+
+    fn into_result(self) -> Result<T, E> {
+        self.into_either().into_result_right()
+    }
+
+    /// Applies `f` to the ok value, or returns `default` for an err value, which is dropped.
+    pub fn map_or<U>(self, default: U, f: impl FnOnce(T) -> U) -> U {
+        self.into_result().map_or(default, f)
+    }
+
+    /// Returns the ok value, or `T::default()` for an err value, which is dropped.
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.into_result().unwrap_or_default()
+    }
+
+    /// Runs `f` on the ok value, if that is the active side, and returns `self` unchanged.
+    pub fn inspect(self, f: impl FnOnce(&T)) -> Self {
+        if IS_OK {
+            f(unsafe { &self.0.ok });
+        }
+        self
+    }
+
+    /// Runs `f` on the err value, if that is the active side, and returns `self` unchanged.
+    pub fn inspect_err(self, f: impl FnOnce(&E)) -> Self {
+        if !IS_OK {
+            f(unsafe { &self.0.err });
+        }
+        self
+    }
+
+    /// Borrows the active side, keeping the same variant.
+    pub fn as_ref(&self) -> ConstResult<&T, &E, IS_OK> {
+        unsafe {
+            if IS_OK {
+                ConstResult::new_ok_unchecked(&*self.0.ok)
+            } else {
+                ConstResult::new_err_unchecked(&*self.0.err)
+            }
+        }
+    }
+
+    /// Mutably borrows the active side, keeping the same variant.
+    pub fn as_mut(&mut self) -> ConstResult<&mut T, &mut E, IS_OK> {
+        unsafe {
+            if IS_OK {
+                ConstResult::new_ok_unchecked(&mut *self.0.ok)
+            } else {
+                ConstResult::new_err_unchecked(&mut *self.0.err)
+            }
+        }
+    }
+}
+
+impl<T, E, const IS_OK: bool> Drop for ConstResult<T, E, IS_OK> {
+    fn drop(&mut self) {
+        unsafe {
+            if IS_OK {
+                drop(ManuallyDrop::take(&mut self.0.ok));
+            } else {
+                drop(ManuallyDrop::take(&mut self.0.err));
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug, E> fmt::Debug for ConstResult<T, E, true> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ok").field(unsafe { &*self.0.ok }).finish()
+    }
+}
+
+impl<T, E: fmt::Debug> fmt::Debug for ConstResult<T, E, false> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Err").field(unsafe { &*self.0.err }).finish()
+    }
+}
+
+impl<T, E: fmt::Display> fmt::Display for ConstResult<T, E, false> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        unsafe { self.0.err.fmt(f) }
+    }
+}
+
+impl<T, E: Error> Error for ConstResult<T, E, false> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        unsafe { self.0.err.source() }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::convert::Infallible;
+    use std::{cell::Cell, convert::Infallible};
 
     use super::*;
 
+    /// A value that keeps track of how many instances of it are alive, so that leaks and double
+    /// drops can be detected.
+    struct Tracked<'a> {
+        id: u64,
+        alive: &'a Cell<usize>,
+    }
+
+    impl<'a> Tracked<'a> {
+        fn new(id: u64, alive: &'a Cell<usize>) -> Self {
+            alive.set(alive.get() + 1);
+            Tracked { id, alive }
+        }
+    }
+
+    impl Drop for Tracked<'_> {
+        fn drop(&mut self) {
+            assert!(self.alive.get() > 0, "value {} was dropped twice", self.id);
+            self.alive.set(self.alive.get() - 1);
+        }
+    }
+
+    impl From<Tracked<'_>> for u64 {
+        fn from(val: Tracked<'_>) -> u64 {
+            val.id
+        }
+    }
+
+    /// A tiny xorshift generator, so the drop harness is deterministic and dependency free.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, bound: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % bound
+        }
+    }
+
+    /// Every shape a tracked value can take while going through the combinators.
+    enum State<'a> {
+        Plain(Tracked<'a>),
+        None(ConstOption<Tracked<'a>, false>),
+        Some(ConstOption<Tracked<'a>, true>),
+        Left(ConstEither<Tracked<'a>, Tracked<'a>, false>),
+        Right(ConstEither<Tracked<'a>, Tracked<'a>, true>),
+    }
+
+    impl<'a> State<'a> {
+        /// Applies a random combinator, returning the new state. `next_id` is used whenever a new
+        /// value needs to be created.
+        fn step(self, rng: &mut Rng, alive: &'a Cell<usize>, next_id: &mut u64) -> Self {
+            let mut fresh = || {
+                *next_id += 1;
+                Tracked::new(*next_id, alive)
+            };
+            match self {
+                State::Plain(val) => match rng.next(4) {
+                    0 => State::Some(ConstOption::<_, true>::new(val)),
+                    1 => State::Left(ConstEither::<_, _, false>::new(val)),
+                    2 => State::Right(ConstEither::<_, _, true>::new(val)),
+                    _ => State::None(ConstOption::<_, false>::new()),
+                },
+                State::None(none) => match rng.next(2) {
+                    0 => State::Some(none.into_some(fresh())),
+                    _ => State::Plain(fresh()),
+                },
+                State::Some(some) => match rng.next(3) {
+                    0 => State::Plain(some.into_inner()),
+                    1 => {
+                        let id = some.id;
+                        let val = ConstOption::<_, true>::new(some.into_inner());
+                        assert_eq!(val.id, id);
+                        State::Some(val)
+                    }
+                    _ => {
+                        drop(some);
+                        State::None(ConstOption::<_, false>::new())
+                    }
+                },
+                State::Left(left) => match rng.next(5) {
+                    0 => State::Right(left.flip()),
+                    1 => State::Plain(left.into_inner()),
+                    2 => State::Plain(left.into_result_left().ok().unwrap()),
+                    3 => State::Plain(left.into_result_right().err().unwrap()),
+                    _ => {
+                        let id = left.id;
+                        assert_eq!(left.into_common::<u64>(), id);
+                        State::Plain(fresh())
+                    }
+                },
+                State::Right(right) => match rng.next(5) {
+                    0 => State::Left(right.flip()),
+                    1 => State::Plain(right.into_inner()),
+                    2 => State::Plain(right.into_result_left().err().unwrap()),
+                    3 => State::Plain(right.into_result_right().ok().unwrap()),
+                    _ => {
+                        let id = right.id;
+                        assert_eq!(right.into_common::<u64>(), id);
+                        State::Plain(fresh())
+                    }
+                },
+            }
+        }
+    }
+
+    #[test]
+    fn drop_balance_random_combinators() {
+        // Kept small enough to run under `cargo miri test` in reasonable time.
+        let (runs, steps) = if cfg!(miri) { (32, 16) } else { (1024, 64) };
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..runs {
+            let alive = Cell::new(0);
+            let mut next_id = 0;
+            let mut state = State::Plain(Tracked::new(next_id, &alive));
+            for _ in 0..steps {
+                state = state.step(&mut rng, &alive, &mut next_id);
+                let expected = if matches!(state, State::None(_)) { 0 } else { 1 };
+                assert_eq!(alive.get(), expected);
+            }
+            drop(state);
+            assert_eq!(alive.get(), 0);
+        }
+    }
+
     #[test]
     fn nothing() {
         let _none = ConstOption::<Infallible, false>::new();
@@ -265,4 +1527,737 @@ mod tests {
         let some = ConstOption::<String, true>::new("Hello, world".to_string());
         assert_eq!(*some, "Hello, world");
     }
+
+    #[test]
+    fn send_sync() {
+        use std::sync::Arc;
+
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<ConstOption<Arc<u8>, true>>();
+        assert_send_sync::<ConstOption<Arc<u8>, false>>();
+        assert_send_sync::<ConstEither<Arc<u8>, String, false>>();
+        assert_send_sync::<ConstEither<String, Arc<u8>, true>>();
+        assert_send_sync::<ConstResult<Arc<u8>, String, true>>();
+    }
+
+    #[test]
+    fn boxed_trait_object() {
+        use std::fmt::Display;
+
+        let mut some = ConstOption::<Box<dyn Display>, true>::new(Box::new(1234));
+        assert_eq!(format!("{}", some.as_deref()), "1234");
+        assert_eq!(format!("{}", &**some), "1234");
+
+        *some = Box::new("hello");
+        assert_eq!(some.as_deref().to_string(), "hello");
+    }
+
+    #[test]
+    fn flatten_std() {
+        assert_eq!(ConstOption::<Option<u8>, true>::new(Some(1)).flatten_std(), Some(1));
+        assert_eq!(ConstOption::<Option<u8>, true>::new(None).flatten_std(), None);
+        assert_eq!(ConstOption::<Option<u8>, false>::new().flatten_std(), None);
+
+        let some = ConstOption::<Option<String>, true>::new(Some("hello".to_string()));
+        assert_eq!(some.flatten_std().as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn and_then_present() {
+        let some = ConstOption::<u8, true>::new(2);
+        let some: ConstOption<String, true> = some.and_then_present(|val| val.to_string());
+        assert_eq!(*some, "2");
+
+        let none = ConstOption::<u8, false>::new();
+        let _none: ConstOption<String, false> =
+            none.and_then_present(|_| panic!("called on an empty option"));
+    }
+
+    #[test]
+    fn transpose() {
+        let some_left = ConstOption::<_, true>::new(ConstEither::<u8, String, false>::new(1));
+        let some_left: ConstEither<ConstOption<u8, true>, ConstOption<String, true>, false> =
+            some_left.transpose();
+        assert_eq!(**some_left, 1);
+
+        let some_right = ConstEither::<u8, String, true>::new("a".to_string());
+        let some_right = ConstOption::<_, true>::new(some_right);
+        let some_right: ConstEither<ConstOption<u8, true>, ConstOption<String, true>, true> =
+            some_right.transpose();
+        assert_eq!(**some_right, "a");
+
+        let none_left = ConstOption::<ConstEither<u8, String, false>, false>::new();
+        let _: ConstEither<ConstOption<u8, false>, ConstOption<String, false>, false> =
+            none_left.transpose();
+
+        let none_right = ConstOption::<ConstEither<u8, String, true>, false>::new();
+        let _: ConstEither<ConstOption<u8, false>, ConstOption<String, false>, true> =
+            none_right.transpose();
+    }
+
+    #[test]
+    fn into_iter_clone() {
+        let mut iter = ConstOption::<String, true>::new("a".to_string()).into_iter();
+        assert_eq!(iter.len(), 1);
+        let mut fork = iter.clone();
+        assert_eq!(iter.next().as_deref(), Some("a"));
+        assert_eq!(fork.next().as_deref(), Some("a"));
+        assert!(iter.next().is_none());
+        assert!(iter.clone().next().is_none());
+        assert!(format!("{:?}", fork).starts_with("IntoIter"));
+
+        let mut none = ConstOption::<String, false>::new().into_iter();
+        assert!(none.clone().next().is_none());
+        assert!(none.next().is_none());
+    }
+
+    #[test]
+    fn map_or_else_ref() {
+        let some = ConstOption::<String, true>::new("hello".to_string());
+        assert_eq!(some.map_or_else_ref(|| 0, |val| val.len()), 5);
+        assert_eq!(*some, "hello");
+
+        let none = ConstOption::<String, false>::new();
+        assert_eq!(none.map_or_else_ref(|| 0, |val| val.len()), 0);
+    }
+
+    #[test]
+    fn unwrap_ref_mut() {
+        let mut some = ConstOption::<String, true>::new("a".to_string());
+        some.unwrap_mut().push('b');
+        assert_eq!(some.unwrap_ref(), "ab");
+    }
+
+    #[test]
+    fn iter_repeat() {
+        let some = ConstOption::<String, true>::new("a".to_string());
+        assert_eq!(some.iter_repeat(3).collect::<Vec<_>>(), ["a", "a", "a"]);
+
+        let none = ConstOption::<String, false>::new();
+        assert_eq!(none.iter_repeat(3).count(), 0);
+    }
+
+    #[test]
+    fn as_ref_array() {
+        let some = ConstOption::<String, true>::new("a".to_string());
+        let [val] = some.as_ref_array();
+        assert_eq!(val, "a");
+
+        let none = ConstOption::<String, false>::new();
+        assert!(none.as_ref_array().is_empty());
+    }
+
+    #[test]
+    fn map_err_to_either() {
+        let some = ConstOption::<u8, true>::new(1);
+        let left: ConstEither<u8, &str, false> = some.map_err_to_either("missing");
+        assert_eq!(*left, 1);
+
+        let none = ConstOption::<u8, false>::new();
+        let right: ConstEither<u8, &str, true> = none.map_err_to_either("missing");
+        assert_eq!(*right, "missing");
+    }
+
+    #[test]
+    fn widen() {
+        let some = ConstOption::<u8, true>::new(1);
+        let left: ConstEither<u8, String, false> = some.widen(|| panic!("called when present"));
+        assert_eq!(*left, 1);
+
+        let none = ConstOption::<u8, false>::new();
+        let right: ConstEither<u8, String, true> = none.widen(|| "computed".to_string());
+        assert_eq!(*right, "computed");
+    }
+
+    #[test]
+    fn ensure_and() {
+        let some = ConstOption::<Vec<u8>, true>::new(vec![1]);
+        let (len, some) = some.ensure_and(|| panic!("called when present"), |vec| {
+            vec.push(2);
+            vec.len()
+        });
+        assert_eq!(len, 2);
+        assert_eq!(*some, [1, 2]);
+
+        let none = ConstOption::<Vec<u8>, false>::new();
+        let (len, some) = none.ensure_and(|| vec![3], |vec| {
+            vec.push(4);
+            vec.len()
+        });
+        assert_eq!(len, 2);
+        assert_eq!(*some, [3, 4]);
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let some = ConstOption::<u8, true>::try_from(vec![1]).ok().unwrap();
+        assert_eq!(*some, 1);
+        assert_eq!(ConstOption::<u8, true>::try_from(vec![]).err(), Some(vec![]));
+        assert_eq!(ConstOption::<u8, true>::try_from(vec![1, 2]).err(), Some(vec![1, 2]));
+
+        assert!(ConstOption::<u8, false>::try_from(vec![]).is_ok());
+        assert_eq!(ConstOption::<u8, false>::try_from(vec![1]).err(), Some(vec![1]));
+    }
+
+    #[test]
+    fn extend_vec() {
+        let mut vec = vec![0];
+        vec.extend([ConstOption::<u8, true>::new(1), ConstOption::<u8, true>::new(2)]);
+        vec.extend([ConstOption::<u8, false>::new(), ConstOption::<u8, false>::new()]);
+        vec.extend(Some(ConstOption::<u8, true>::new(3)));
+        assert_eq!(vec, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn into_inner_or() {
+        fn extract<const IS_SOME: bool>(opt: ConstOption<String, IS_SOME>) -> String {
+            opt.into_inner_or(|| "recovered".to_string())
+        }
+
+        assert_eq!(extract(ConstOption::<_, true>::new("a".to_string())), "a");
+        assert_eq!(extract(ConstOption::<_, false>::new()), "recovered");
+        assert_eq!(ConstOption::<String, true>::new("b".to_string()).into_inner_or_default(), "b");
+        assert_eq!(ConstOption::<String, false>::new().into_inner_or_default(), "");
+    }
+
+    #[test]
+    fn try_into_inner() {
+        let alive = Cell::new(0);
+        let some = ConstOption::<_, true>::new(Tracked::new(1, &alive));
+        let val = some.try_into_inner().unwrap();
+        assert_eq!(val.id, 1);
+        assert_eq!(alive.get(), 1);
+        drop(val);
+        assert_eq!(alive.get(), 0);
+
+        assert!(ConstOption::<Tracked, false>::new().try_into_inner().is_err());
+    }
+
+    #[test]
+    fn validate() {
+        let some = ConstOption::<u8, true>::new(1);
+        let ok: ConstResult<u8, &str, true> = some.validate("missing");
+        assert_eq!(ok.map_or(0, |val| val + 1), 2);
+
+        let none = ConstOption::<u8, false>::new();
+        let err: ConstResult<u8, &str, false> = none.validate("missing");
+        assert_eq!(err.unwrap_err(), "missing");
+
+        let some = ConstOption::<u8, true>::new(1);
+        let ok = some.validate_with(|| -> String { panic!("called when present") });
+        assert_eq!(ok.unwrap(), 1);
+        let err = ConstOption::<u8, false>::new().validate_with(|| "missing".to_string());
+        assert_eq!(err.unwrap_err(), "missing");
+    }
+
+    #[test]
+    fn into_const() {
+        let some = ConstOption::<String, true>::new("a".to_string());
+        let some = some.into_const::<true>().ok().unwrap();
+        let some = some.into_const::<false>().err().unwrap();
+        assert_eq!(*some, "a");
+
+        let none = ConstOption::<String, false>::new();
+        let none = none.into_const::<false>().ok().unwrap();
+        assert!(none.into_const::<true>().is_err());
+    }
+
+    #[test]
+    fn modify_if_present() {
+        let mut some = ConstOption::<u8, true>::new(1);
+        some.modify_if_present(|val| *val += 1);
+        assert_eq!(*some, 2);
+
+        let mut none = ConstOption::<u8, false>::new();
+        none.modify_if_present(|_| panic!("called on an empty option"));
+    }
+
+    #[test]
+    fn replace_if() {
+        let alive = Cell::new(0);
+        let some = ConstOption::<_, true>::new(Tracked::new(1, &alive));
+
+        let (old, some) = some.replace_if(Tracked::new(2, &alive), |val| val.id == 1);
+        assert_eq!(old.map(|val| val.id), Some(1));
+        assert_eq!(some.id, 2);
+        assert_eq!(alive.get(), 1);
+
+        let (old, some) = some.replace_if(Tracked::new(3, &alive), |val| val.id == 1);
+        assert!(old.is_none());
+        assert_eq!(some.id, 2);
+        assert_eq!(alive.get(), 1);
+
+        drop(some);
+        assert_eq!(alive.get(), 0);
+    }
+
+    #[test]
+    fn into_nothing() {
+        let none = ConstOption::<String, false>::new();
+        let () = none.into_nothing();
+    }
+
+    #[test]
+    fn unwrap_all() {
+        let arr = [1, 2, 3].map(ConstOption::<u8, true>::new);
+        assert_eq!(super::unwrap_all(arr), [1, 2, 3]);
+
+        fn filled<const IS_SOME: bool>(arr: [ConstOption<String, IS_SOME>; 2]) -> [String; 2] {
+            super::unwrap_all_or(arr, "default".to_string())
+        }
+
+        let present = ["a", "b"].map(|val| ConstOption::<_, true>::new(val.to_string()));
+        assert_eq!(filled(present), ["a", "b"]);
+        let absent = [ConstOption::<String, false>::new(), ConstOption::<String, false>::new()];
+        assert_eq!(filled(absent), ["default", "default"]);
+    }
+
+    #[test]
+    fn try_collect_const() {
+        let all_ok = super::try_collect_const([Ok::<u8, String>(1), Ok(2)]);
+        assert_eq!(*all_ok.unwrap(), [1, 2]);
+
+        let mut consumed = 0;
+        let early_err = super::try_collect_const(
+            [Ok(1), Err("bad".to_string()), Ok(3)].into_iter().inspect(|_| consumed += 1),
+        );
+        assert_eq!(early_err.err().as_deref(), Some("bad"));
+        assert_eq!(consumed, 2);
+    }
+
+    #[test]
+    fn inner_iter() {
+        fn sum<const IS_SOME: bool>(opt: ConstOption<std::ops::Range<u8>, IS_SOME>) -> u8 {
+            opt.inner_iter().sum()
+        }
+
+        assert_eq!(sum(ConstOption::<_, true>::new(1..4)), 6);
+        assert_eq!(sum(ConstOption::<_, false>::new()), 0);
+
+        let mut iter = ConstOption::<_, true>::new(1..5).inner_iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.by_ref().take(2).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.collect::<Vec<_>>(), [3]);
+        assert_eq!(ConstOption::<std::ops::Range<u8>, false>::new().inner_iter().len(), 0);
+
+        // `by_ref` also reaches the inner iterator through `DerefMut`.
+        let mut some = ConstOption::<_, true>::new(1..5);
+        assert_eq!(some.by_ref().take(2).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(some.into_iter().next(), Some(3..5));
+    }
+
+    #[test]
+    fn binary_heap() {
+        let mut heap = ConstOption::<BinaryHeap<i32>, true>::new(BinaryHeap::new());
+        assert_eq!(heap.peek_inner(), None);
+        heap.push(1);
+        heap.push(5);
+        heap.push(3);
+        assert_eq!(heap.peek_inner(), Some(&5));
+        assert_eq!(heap.pop(), Some(5));
+        assert_eq!(heap.pop(), Some(3));
+        assert_eq!(heap.peek_inner(), Some(&1));
+    }
+
+    #[test]
+    fn cow() {
+        let mut borrowed = ConstOption::<Cow<str>, true>::new(Cow::Borrowed("a"));
+        borrowed.to_mut().push('b');
+        assert!(matches!(*borrowed, Cow::Owned(_)));
+        assert_eq!(*borrowed.into_owned(), "ab");
+
+        let owned = ConstOption::<Cow<str>, true>::new(Cow::Owned("c".to_string()));
+        let owned: ConstOption<String, true> = owned.into_owned();
+        assert_eq!(*owned, "c");
+
+        let borrowed = ConstOption::<Cow<[u8]>, true>::new(Cow::Borrowed(&[1, 2]));
+        assert_eq!(*borrowed.into_owned(), vec![1, 2]);
+    }
+
+    #[test]
+    fn get_or_static_default() {
+        let some = ConstOption::<u64, true>::new(1);
+        assert_eq!(*some.get_or_static_default(), 1);
+
+        let none = ConstOption::<u64, false>::new();
+        let other_none = ConstOption::<u64, false>::new();
+        let first = none.get_or_static_default();
+        let second = other_none.get_or_static_default();
+        assert_eq!(*first, 0);
+        assert!(std::ptr::eq(first, second));
+
+        let other = ConstOption::<u8, false>::new();
+        assert_eq!(*other.get_or_static_default(), 0);
+
+        // The present variant doesn't need `ConstDefault`.
+        let string = ConstOption::<String, true>::new("a".to_string());
+        assert_eq!(string.get_or_static_default(), "a");
+    }
+
+    #[test]
+    fn into_some() {
+        let alive = Cell::new(0);
+        let none = ConstOption::<Tracked, false>::new();
+        let some = none.into_some(Tracked::new(1, &alive));
+        assert_eq!(some.id, 1);
+        assert_eq!(alive.get(), 1);
+        drop(some);
+        assert_eq!(alive.get(), 0);
+    }
+
+    #[test]
+    fn into_parts_round_trip() {
+        let alive = Cell::new(0);
+        let some = ConstOption::<_, true>::new(Tracked::new(1, &alive));
+        let (val, empty) = some.into_parts();
+        assert_eq!(val.id, 1);
+        assert_eq!(alive.get(), 1);
+
+        let some = ConstOption::<_, true>::from_parts(val, empty);
+        assert_eq!(some.id, 1);
+        assert_eq!(alive.get(), 1);
+        drop(some);
+        assert_eq!(alive.get(), 0);
+    }
+
+    #[test]
+    fn const_or_default() {
+        const ZERO: ConstOption<u32, true> = ConstOption::<u32, false>::new().or_default();
+        assert_eq!(*ZERO, 0);
+        assert_eq!(*ConstOption::<i8, false>::new().or_default(), 0);
+    }
+
+    #[test]
+    fn either_into_common() {
+        let left = ConstEither::<u8, u16, false>::new(12);
+        let right = ConstEither::<u8, u16, true>::new(1234);
+        assert_eq!(left.into_common::<u32>(), 12u32);
+        assert_eq!(right.into_common::<u32>(), 1234u32);
+
+        let right = ConstEither::<&str, String, true>::new("hello".to_string());
+        assert_eq!(right.into_common::<String>(), "hello");
+    }
+
+    #[test]
+    fn either_into_common_or_default() {
+        let left = ConstEither::<&str, char, false>::new("left");
+        let right = ConstEither::<&str, char, true>::new('r');
+        assert_eq!(left.into_common_or_default::<String>(), "left");
+        assert_eq!(right.into_common_or_default::<String>(), "r");
+    }
+
+    #[test]
+    fn either_into_result() {
+        let left = ConstEither::<String, u8, false>::new("left".to_string());
+        assert_eq!(left.into_result_left(), Ok("left".to_string()));
+        let left = ConstEither::<String, u8, false>::new("left".to_string());
+        assert_eq!(left.into_result_right(), Err("left".to_string()));
+
+        let right = ConstEither::<u8, String, true>::new("right".to_string());
+        assert_eq!(right.into_result_left(), Err("right".to_string()));
+        let right = ConstEither::<u8, String, true>::new("right".to_string());
+        assert_eq!(right.into_result_right(), Ok("right".to_string()));
+    }
+
+    #[test]
+    fn either_as_mut_either() {
+        let mut left = ConstEither::<String, u8, false>::new("a".to_string());
+        left.as_mut_either().into_inner().push('b');
+        assert_eq!(*left, "ab");
+
+        let mut right = ConstEither::<String, u8, true>::new(1);
+        **right.as_mut_either() += 1;
+        assert_eq!(*right, 2);
+    }
+
+    #[test]
+    fn either_size_metrics() {
+        let left = ConstEither::<u8, [u8; 16], false>::new(1);
+        assert_eq!(left.active_size(), 1);
+        assert_eq!(left.wasted_bytes(), 15);
+
+        let right = ConstEither::<u8, [u8; 16], true>::new([0; 16]);
+        assert_eq!(right.active_size(), 16);
+        assert_eq!(right.wasted_bytes(), 0);
+    }
+
+    #[test]
+    fn either_zip() {
+        let left = ConstEither::<u8, String, false>::new(1);
+        let other = ConstEither::<&str, Vec<u8>, false>::new("a");
+        let pair: ConstEither<(u8, &str), (String, Vec<u8>), false> = left.zip(other);
+        assert_eq!(*pair, (1, "a"));
+
+        let right = ConstEither::<u8, String, true>::new("b".to_string());
+        let other = ConstEither::<&str, Vec<u8>, true>::new(vec![2]);
+        let pair: ConstEither<(u8, &str), (String, Vec<u8>), true> = right.zip(other);
+        assert_eq!(*pair, ("b".to_string(), vec![2]));
+    }
+
+    #[test]
+    fn either_flip_ref() {
+        let left = ConstEither::<String, u8, false>::new("left".to_string());
+        let flipped: ConstEither<&u8, &String, true> = left.flip_ref();
+        assert_eq!(*flipped, "left");
+        assert_eq!(*left, "left");
+        drop(flipped);
+        assert_eq!(left.into_inner(), "left");
+
+        let right = ConstEither::<u8, String, true>::new("right".to_string());
+        let flipped: ConstEither<&String, &u8, false> = right.flip_ref();
+        assert_eq!(*flipped, "right");
+        assert_eq!(*right, "right");
+        drop(flipped);
+        assert_eq!(right.into_inner(), "right");
+    }
+
+    #[test]
+    fn either_left_or_right_or() {
+        let alive = Cell::new(0);
+        let left = || ConstEither::<Tracked, Tracked, false>::new(Tracked::new(1, &alive));
+        let right = || ConstEither::<Tracked, Tracked, true>::new(Tracked::new(2, &alive));
+        let default = || Tracked::new(3, &alive);
+
+        assert_eq!(left().left_or(default()).id, 1);
+        assert_eq!(left().right_or(default()).id, 3);
+        assert_eq!(right().left_or(default()).id, 3);
+        assert_eq!(right().right_or(default()).id, 2);
+        assert_eq!(alive.get(), 0);
+    }
+
+    #[test]
+    fn either_reinterpret_same() {
+        let alive = Cell::new(0);
+        let left = ConstEither::<Tracked, Tracked, false>::new(Tracked::new(1, &alive));
+        let right: ConstEither<Tracked, Tracked, true> = left.reinterpret_same();
+        assert_eq!(right.id, 1);
+        let right = right.reinterpret_same::<true>();
+        let left = right.reinterpret_same::<false>();
+        assert_eq!(left.into_inner().id, 1);
+        assert_eq!(alive.get(), 0);
+    }
+
+    #[test]
+    fn either_enumerate_map() {
+        let left = ConstEither::<Vec<u8>, Vec<&str>, false>::new(vec![10, 20]);
+        let left = left.enumerate_map(|i, val| i + val as usize, |i, val| format!("{i}{val}"));
+        assert_eq!(*left, [10, 21]);
+
+        let right = ConstEither::<Vec<u8>, Vec<&str>, true>::new(vec!["a", "b"]);
+        let right = right.enumerate_map(|i, val| i + val as usize, |i, val| format!("{i}{val}"));
+        assert_eq!(*right, ["0a", "1b"]);
+    }
+
+    #[test]
+    fn either_as_slice() {
+        let left = ConstEither::<Vec<u8>, [u8; 4], false>::new(vec![1, 2]);
+        let right = ConstEither::<Vec<u8>, [u8; 4], true>::new([1, 2, 3, 4]);
+        assert_eq!(left.as_slice(), &[1, 2]);
+        assert_eq!(right.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn either_borrow_active() {
+        let left = ConstEither::<String, Box<str>, false>::new("left".to_string());
+        let right = ConstEither::<String, Box<str>, true>::new("right".into());
+        assert_eq!(left.borrow_active::<str>(), "left");
+        assert_eq!(right.borrow_active::<str>(), "right");
+    }
+
+    #[test]
+    fn either_default() {
+        use std::collections::HashSet;
+
+        #[derive(Default)]
+        struct Container {
+            data: ConstEither<Vec<u8>, HashSet<u8>, false>,
+        }
+
+        let container = Container::default();
+        assert!(container.data.is_empty());
+    }
+
+    #[test]
+    fn either_tuple_of_options() {
+        let left = ConstEither::<String, u8, false>::new("left".to_string());
+        let (some, _none): (ConstOption<String, true>, ConstOption<u8, false>) = left.into();
+        assert_eq!(*some, "left");
+        let left: ConstEither<String, u8, false> = (some, _none).into();
+        assert_eq!(*left, "left");
+
+        let right = ConstEither::<u8, String, true>::new("right".to_string());
+        let (_none, some): (ConstOption<u8, false>, ConstOption<String, true>) = right.into();
+        assert_eq!(*some, "right");
+        let right: ConstEither<u8, String, true> = (_none, some).into();
+        assert_eq!(*right, "right");
+    }
+
+    #[test]
+    fn either_hash_eq_active_side() {
+        use std::collections::HashSet;
+
+        struct NoHash;
+
+        let mut set = HashSet::new();
+        set.insert(ConstEither::<u8, NoHash, false>::new(1));
+        set.insert(ConstEither::<u8, NoHash, false>::new(2));
+        set.insert(ConstEither::<u8, NoHash, false>::new(1));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&ConstEither::<u8, NoHash, false>::new(2)));
+
+        assert!(ConstEither::<NoHash, u8, true>::new(3) == ConstEither::<NoHash, u8, true>::new(3));
+        assert!(ConstEither::<NoHash, u8, true>::new(3) != ConstEither::<NoHash, u8, true>::new(4));
+    }
+
+    #[test]
+    fn result_unwrap() {
+        assert_eq!(ConstResult::<String, u8, true>::new("ok".to_string()).unwrap(), "ok");
+        assert_eq!(ConstResult::<String, u8, true>::new("ok".to_string()).expect("is ok"), "ok");
+        assert_eq!(ConstResult::<u8, String, false>::new("err".to_string()).unwrap_err(), "err");
+        assert_eq!(
+            ConstResult::<u8, String, false>::new("err".to_string()).expect_err("is err"),
+            "err"
+        );
+    }
+
+    #[test]
+    fn result_as_ref_as_mut() {
+        let mut ok = ConstResult::<String, u8, true>::new("ok".to_string());
+        let borrowed: ConstResult<&String, &u8, true> = ok.as_ref();
+        assert_eq!(borrowed.into_ok(), "ok");
+        ok.as_mut().into_ok().push('!');
+        assert_eq!(ok.into_ok(), "ok!");
+
+        let mut err = ConstResult::<u8, String, false>::new("err".to_string());
+        let borrowed: ConstResult<&u8, &String, false> = err.as_ref();
+        assert_eq!(borrowed.into_err(), "err");
+        err.as_mut().into_err().push('!');
+        assert_eq!(err.into_err(), "err!");
+    }
+
+    #[test]
+    fn flatten_into_vec() {
+        let column = vec![ConstOption::<u8, true>::new(1), ConstOption::<u8, true>::new(2)];
+        let some = ConstOption::<_, true>::new(column);
+        assert_eq!(some.flatten_into_vec(), vec![1, 2]);
+
+        let none = ConstOption::<Vec<ConstOption<u8, true>>, false>::new();
+        assert_eq!(none.flatten_into_vec(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn result_either_round_trip() {
+        let ok = ConstResult::<String, u8, true>::new("ok".to_string());
+        let right: ConstEither<u8, String, true> = ok.into_either();
+        assert_eq!(*right, "ok");
+        assert_eq!(ConstResult::from_either(right).into_ok(), "ok");
+
+        let err = ConstResult::<u8, String, false>::new("err".to_string());
+        let left: ConstEither<String, u8, false> = err.into_either();
+        assert_eq!(*left, "err");
+        assert_eq!(ConstResult::from_either(left).into_err(), "err");
+    }
+
+    #[test]
+    fn result_map_or_unwrap_or_default() {
+        let ok = || ConstResult::<String, String, true>::new("ok".to_string());
+        let err = || ConstResult::<String, String, false>::new("err".to_string());
+
+        assert_eq!(ok().map_or(0, |val| val.len()), 2);
+        assert_eq!(err().map_or(0, |val| val.len()), 0);
+        assert_eq!(ok().unwrap_or_default(), "ok");
+        assert_eq!(err().unwrap_or_default(), "");
+    }
+
+    #[test]
+    fn option_min_max() {
+        let some = |val: u8| ConstOption::<u8, true>::new(val);
+        let none = || ConstOption::<u8, false>::new();
+
+        assert_eq!(some(1).max(some(2)).into_inner(), 2);
+        assert_eq!(some(1).min(some(2)).into_inner(), 1);
+        assert_eq!(some(3).max(none()).into_inner(), 3);
+        assert_eq!(some(3).min(none()).into_inner(), 3);
+        assert_eq!(none().max(some(4)).into_inner(), 4);
+        assert_eq!(none().min(some(4)).into_inner(), 4);
+        none().max(none()).into_nothing();
+        none().min(none()).into_nothing();
+    }
+
+    #[test]
+    fn result_inspect() {
+        let mut seen = Vec::new();
+        let ok = ConstResult::<u8, u8, true>::new(1)
+            .inspect(|val| seen.push(("ok", *val)))
+            .inspect_err(|_| panic!("called on an ok value"));
+        assert_eq!(ok.into_ok(), 1);
+
+        let err = ConstResult::<u8, u8, false>::new(2)
+            .inspect(|_| panic!("called on an err value"))
+            .inspect_err(|val| seen.push(("err", *val)));
+        assert_eq!(err.into_err(), 2);
+        assert_eq!(seen, [("ok", 1), ("err", 2)]);
+    }
+
+    #[test]
+    fn result_formatting() {
+        use std::fmt;
+
+        #[derive(Debug)]
+        struct NotFound;
+
+        impl fmt::Display for NotFound {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "not found")
+            }
+        }
+
+        impl Error for NotFound {}
+
+        struct NoDebug;
+
+        let ok = ConstResult::<u32, NoDebug, true>::new(42);
+        assert_eq!(format!("{:?}", ok), "Ok(42)");
+
+        let err = ConstResult::<NoDebug, NotFound, false>::new(NotFound);
+        assert_eq!(format!("{:?}", err), "Err(NotFound)");
+        assert_eq!(err.to_string(), "not found");
+
+        let boxed: Box<dyn Error> = Box::new(err);
+        assert_eq!(boxed.to_string(), "not found");
+        assert!(boxed.source().is_none());
+    }
+
+    /// Exercises `flip` on values with destructors and heap allocations. These are meant to be run
+    /// under `cargo miri test` as well, which checks for UB and leaks.
+    mod flip {
+        use super::*;
+
+        #[test]
+        fn round_trip_drop_type() {
+            let alive = Cell::new(0);
+            let left = ConstEither::<Tracked, Tracked, false>::new(Tracked::new(7, &alive));
+            let right = left.flip();
+            assert_eq!(right.id, 7);
+            let left = right.flip();
+            assert_eq!(left.id, 7);
+            assert_eq!(alive.get(), 1);
+            drop(left);
+            assert_eq!(alive.get(), 0);
+        }
+
+        #[test]
+        fn non_copy_differently_sized() {
+            let left = ConstEither::<Vec<String>, u8, false>::new(vec!["a".to_string(); 3]);
+            let right: ConstEither<u8, Vec<String>, true> = left.flip();
+            assert_eq!(*right, ["a", "a", "a"]);
+
+            let right = ConstEither::<[u64; 4], Box<str>, true>::new("boxed".into());
+            let left: ConstEither<Box<str>, [u64; 4], false> = right.flip();
+            assert_eq!(&**left, "boxed");
+            assert_eq!(&*left.flip().into_inner(), "boxed");
+        }
+    }
 }
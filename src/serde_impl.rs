@@ -0,0 +1,213 @@
+//! `serde` support for `ConstEither`, behind the `serde` feature.
+//!
+//! By default, a `ConstEither` is serialized like an externally tagged enum with `Left` and
+//! `Right` variants, e.g. `{"Right":10}`. The [`AdjacentlyTagged`] and [`Untagged`] wrappers
+//! provide the other representations, `{"tag":"Right","content":10}` and `10`. Only the active
+//! side needs to implement `Serialize`/`Deserialize`, and deserializing the wrong variant for the
+//! tagged representations is an error.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, EnumAccess, VariantAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::ConstEither;
+
+/// Uses the adjacently tagged representation, `{"tag":"Left","content":..}`, when serializing or
+/// deserializing the inner either.
+pub struct AdjacentlyTagged<L, R, const IS_RIGHT: bool>(pub ConstEither<L, R, IS_RIGHT>);
+
+/// Uses the untagged representation, just the active side, when serializing or deserializing the
+/// inner either.
+pub struct Untagged<L, R, const IS_RIGHT: bool>(pub ConstEither<L, R, IS_RIGHT>);
+
+const NAME: &str = "ConstEither";
+const VARIANTS: &[&str] = &["Left", "Right"];
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(variant_identifier)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    fn name(self) -> &'static str {
+        VARIANTS[self as usize]
+    }
+
+    fn check<E: de::Error>(self, expected: Side) -> Result<(), E> {
+        if self == expected {
+            Ok(())
+        } else {
+            Err(E::custom(format_args!(
+                "expected the `{}` variant, found `{}`",
+                expected.name(),
+                self.name()
+            )))
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "ConstEither")]
+struct AdjacentRef<'a, T> {
+    tag: &'static str,
+    content: &'a T,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "ConstEither")]
+struct Adjacent<T> {
+    tag: Side,
+    content: T,
+}
+
+struct TaggedVisitor<T> {
+    expected: Side,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for TaggedVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a `{}` variant of `ConstEither`", self.expected.name())
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<T, A::Error> {
+        let (side, variant) = data.variant::<Side>()?;
+        side.check(self.expected)?;
+        variant.newtype_variant()
+    }
+}
+
+macro_rules! impl_serde {
+    ($is_right:literal, $active:ident, $side:ident) => {
+        impl<L, R> Serialize for ConstEither<L, R, $is_right>
+        where
+            $active: Serialize,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let side = Side::$side;
+                serializer.serialize_newtype_variant(NAME, side as u32, side.name(), &**self)
+            }
+        }
+
+        impl<'de, L, R> Deserialize<'de> for ConstEither<L, R, $is_right>
+        where
+            $active: Deserialize<'de>,
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let visitor = TaggedVisitor::<$active> {
+                    expected: Side::$side,
+                    marker: PhantomData,
+                };
+                deserializer.deserialize_enum(NAME, VARIANTS, visitor).map(Self::new)
+            }
+        }
+
+        impl<L, R> Serialize for AdjacentlyTagged<L, R, $is_right>
+        where
+            $active: Serialize,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                AdjacentRef { tag: Side::$side.name(), content: &*self.0 }.serialize(serializer)
+            }
+        }
+
+        impl<'de, L, R> Deserialize<'de> for AdjacentlyTagged<L, R, $is_right>
+        where
+            $active: Deserialize<'de>,
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let Adjacent { tag, content } = Adjacent::<$active>::deserialize(deserializer)?;
+                tag.check(Side::$side)?;
+                Ok(AdjacentlyTagged(ConstEither::<L, R, $is_right>::new(content)))
+            }
+        }
+
+        impl<L, R> Serialize for Untagged<L, R, $is_right>
+        where
+            $active: Serialize,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                (*self.0).serialize(serializer)
+            }
+        }
+
+        impl<'de, L, R> Deserialize<'de> for Untagged<L, R, $is_right>
+        where
+            $active: Deserialize<'de>,
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let val = $active::deserialize(deserializer)?;
+                Ok(Untagged(ConstEither::<L, R, $is_right>::new(val)))
+            }
+        }
+    };
+}
+
+impl_serde!(false, L, Left);
+impl_serde!(true, R, Right);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoSerde;
+
+    #[test]
+    fn externally_tagged() {
+        let left = ConstEither::<u8, NoSerde, false>::new(1);
+        let json = serde_json::to_string(&left).unwrap();
+        assert_eq!(json, r#"{"Left":1}"#);
+        let left: ConstEither<u8, NoSerde, false> = serde_json::from_str(&json).unwrap();
+        assert_eq!(*left, 1);
+
+        let right = ConstEither::<NoSerde, String, true>::new("a".to_string());
+        let json = serde_json::to_string(&right).unwrap();
+        assert_eq!(json, r#"{"Right":"a"}"#);
+        let right: ConstEither<NoSerde, String, true> = serde_json::from_str(&json).unwrap();
+        assert_eq!(*right, "a");
+
+        let err = serde_json::from_str::<ConstEither<u8, u8, true>>(r#"{"Left":1}"#).err();
+        assert!(err.unwrap().to_string().contains("expected the `Right` variant"));
+    }
+
+    #[test]
+    fn adjacently_tagged() {
+        let left = AdjacentlyTagged(ConstEither::<u8, NoSerde, false>::new(1));
+        let json = serde_json::to_string(&left).unwrap();
+        assert_eq!(json, r#"{"tag":"Left","content":1}"#);
+        let left: AdjacentlyTagged<u8, NoSerde, false> = serde_json::from_str(&json).unwrap();
+        assert_eq!(*left.0, 1);
+
+        let right = AdjacentlyTagged(ConstEither::<NoSerde, String, true>::new("a".to_string()));
+        let json = serde_json::to_string(&right).unwrap();
+        assert_eq!(json, r#"{"tag":"Right","content":"a"}"#);
+        let right: AdjacentlyTagged<NoSerde, String, true> = serde_json::from_str(&json).unwrap();
+        assert_eq!(*right.0, "a");
+
+        let json = r#"{"tag":"Right","content":1}"#;
+        let err = serde_json::from_str::<AdjacentlyTagged<u8, u8, false>>(json).err();
+        assert!(err.unwrap().to_string().contains("expected the `Left` variant"));
+    }
+
+    #[test]
+    fn untagged() {
+        let left = Untagged(ConstEither::<u8, NoSerde, false>::new(1));
+        let json = serde_json::to_string(&left).unwrap();
+        assert_eq!(json, "1");
+        let left: Untagged<u8, NoSerde, false> = serde_json::from_str(&json).unwrap();
+        assert_eq!(*left.0, 1);
+
+        let right = Untagged(ConstEither::<NoSerde, String, true>::new("a".to_string()));
+        let json = serde_json::to_string(&right).unwrap();
+        assert_eq!(json, r#""a""#);
+        let right: Untagged<NoSerde, String, true> = serde_json::from_str(&json).unwrap();
+        assert_eq!(*right.0, "a");
+    }
+}
@@ -0,0 +1,131 @@
+//! `?` support for `ConstOption`, behind the `nightly` feature.
+//!
+//! Using `?` on a `ConstOption` yields its inner value, or returns early with an empty residual
+//! that converts into `None` or an empty `ConstOption`. A present option never short-circuits, so
+//! `?` on a `ConstOption<T, true>` is a no-op that just unwraps it, and on a
+//! `ConstOption<T, false>` it always returns early. An empty option can't be propagated out of a
+//! function returning `ConstOption<T, true>`, since that would need a value to build one from, so
+//! doing so is a compile error. Options with a generic flag can be used with `?` in functions
+//! returning an `Option` or a `ConstOption<T, false>`.
+//!
+//! # Lossy `from_output`
+//!
+//! `Try::from_output` has nowhere to store its value for the empty variant, so it drops it. This
+//! breaks the usual `Try` law that `branch(from_output(x))` is `Continue(x)`, since for
+//! `ConstOption<T, false>` it is always `Break`. `?` itself never calls `from_output`, but `try`
+//! blocks and adapters like `Iterator::try_fold` do, so those shouldn't be used with the empty
+//! variant.
+
+use std::{
+    convert::Infallible,
+    ops::{ControlFlow, FromResidual, Residual, Try},
+};
+
+use crate::ConstOption;
+
+impl<T, const IS_SOME: bool> Try for ConstOption<T, IS_SOME> {
+    type Output = T;
+    type Residual = ConstOption<Infallible, IS_SOME>;
+
+    /// Wraps `output` in a present option. For the empty variant there is nowhere to put it, so
+    /// it is dropped. See the module docs.
+    fn from_output(output: T) -> Self {
+        unsafe { ConstOption::from_option_unchecked(IS_SOME.then_some(output)) }
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, T> {
+        match self.into_option() {
+            Some(val) => ControlFlow::Continue(val),
+            None => ControlFlow::Break(unsafe { ConstOption::from_option_unchecked(None) }),
+        }
+    }
+}
+
+mod sealed {
+    use crate::ConstOption;
+
+    /// Implemented for the options that the residual of a `ConstOption<_, FROM>` can be
+    /// propagated into: any option when propagating from a present one, since that never
+    /// short-circuits, and only empty ones when propagating from an empty one.
+    ///
+    /// These are two overlapping impls, which is why this is a marker trait, but together they
+    /// cover both the same-flag case needed by `Try` and propagating a generic flag into an empty
+    /// option.
+    #[marker]
+    pub trait AcceptsResidual<const FROM: bool> {}
+
+    impl<T, const IS_SOME: bool> AcceptsResidual<IS_SOME> for ConstOption<T, IS_SOME> {}
+
+    impl<T, const FROM: bool> AcceptsResidual<FROM> for ConstOption<T, false> {}
+}
+
+impl<T, const IS_SOME: bool, const FROM: bool> FromResidual<ConstOption<Infallible, FROM>>
+    for ConstOption<T, IS_SOME>
+where
+    Self: sealed::AcceptsResidual<FROM>,
+{
+    fn from_residual(residual: ConstOption<Infallible, FROM>) -> Self {
+        match residual.into_option() {
+            Some(never) => match never {},
+            // Only an empty residual is constructible, and `AcceptsResidual` only allows those to
+            // be propagated into empty options, so `IS_SOME` is `false` here.
+            None => unsafe { ConstOption::from_option_unchecked(None) },
+        }
+    }
+}
+
+impl<T, const IS_SOME: bool> Residual<T> for ConstOption<Infallible, IS_SOME> {
+    type TryType = ConstOption<T, IS_SOME>;
+}
+
+impl<T, const IS_SOME: bool> FromResidual<ConstOption<Infallible, IS_SOME>> for Option<T> {
+    fn from_residual(residual: ConstOption<Infallible, IS_SOME>) -> Self {
+        match residual.into_option() {
+            Some(never) => match never {},
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn add_one<const IS_SOME: bool>(val: ConstOption<u8, IS_SOME>) -> Option<u8> {
+        Some(val? + 1)
+    }
+
+    fn propagate<const IS_SOME: bool>(
+        val: ConstOption<u8, IS_SOME>,
+        reached: &Cell<bool>,
+    ) -> ConstOption<u8, false> {
+        val?;
+        reached.set(true);
+        ConstOption::<u8, false>::new()
+    }
+
+    fn add_one_present(val: ConstOption<u8, true>) -> ConstOption<u8, true> {
+        ConstOption::<u8, true>::new(val? + 1)
+    }
+
+    #[test]
+    fn present_is_a_no_op() {
+        assert_eq!(add_one(ConstOption::<u8, true>::new(1)), Some(2));
+        assert_eq!(add_one_present(ConstOption::<u8, true>::new(1)).into_inner(), 2);
+
+        let reached = Cell::new(false);
+        propagate(ConstOption::<u8, true>::new(1), &reached).into_nothing();
+        assert!(reached.get());
+    }
+
+    #[test]
+    fn empty_returns_early() {
+        assert_eq!(add_one(ConstOption::<u8, false>::new()), None);
+
+        let reached = Cell::new(false);
+        propagate(ConstOption::<u8, false>::new(), &reached).into_nothing();
+        assert!(!reached.get());
+    }
+}
@@ -0,0 +1,11 @@
+// Optional snapshot tests of the exact compiler output, e.g. the `assert_some` panic message. The
+// negative `Send`/`Sync` checks are `compile_fail` doctests instead, which don't depend on the
+// toolchain. The `.stderr` files were generated with rustc 1.95.0; run these with
+// `cargo test -- --ignored` on that toolchain, and regenerate them with `TRYBUILD=overwrite` when
+// updating it.
+#[test]
+#[ignore = "compiler output depends on the toolchain"]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,7 @@
+use const_either::assert_some;
+
+// trybuild runs `cargo check`, which evaluates `const` items but not inline `const` blocks, so the
+// assertion goes through an item here.
+const _: () = assert_some::<false>();
+
+fn main() {}